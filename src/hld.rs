@@ -0,0 +1,223 @@
+use super::*;
+use gen_id::Entity;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// Heavy-light decomposition of the forest stored in a [`RangeRelations`].
+///
+/// Each node is assigned a contiguous `pos` in a linearized array by always
+/// descending into its heaviest child first, so that any root-to-node path is
+/// covered by `O(log n)` contiguous position ranges ("chain segments") and any
+/// subtree occupies a single contiguous range. Callers back the linearized
+/// array with their own Fenwick/segment tree over a `RawComponent` and use the
+/// positions and segments exposed here to drive path and subtree aggregates.
+#[derive(Debug, Clone)]
+pub struct HldIndex<E: Entity> {
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+    size: Vec<usize>,
+    head: Vec<usize>,
+    pos: Vec<usize>,
+    marker: PhantomData<E>,
+}
+
+impl<E: Entity> HldIndex<E> {
+    /// Builds the decomposition from a [`RangeRelations`] forest.
+    pub fn new(relations: &RangeRelations<E>) -> Self {
+        let elements = relations.into_iter().count();
+
+        // Lower the relations into dense row-indexed adjacency so the traversal
+        // works purely in position space.
+        let mut children = vec![Vec::new(); elements];
+        let mut parent = vec![None; elements];
+        for (row, relation) in relations.into_iter().enumerate() {
+            match relation {
+                RangeRelation::ParentOf(range) => {
+                    children[row].extend((*range).into_iter().map(|id| id.index()));
+                }
+                RangeRelation::ChildOf(p) => parent[row] = Some(p.index()),
+            }
+        }
+
+        let mut index = Self {
+            parent,
+            depth: vec![0; elements],
+            size: vec![1; elements],
+            head: vec![0; elements],
+            pos: vec![0; elements],
+            marker: PhantomData,
+        };
+
+        // Heaviest child first keeps each heavy chain contiguous in `pos`.
+        for root in 0..elements {
+            if index.parent[root].is_none() {
+                index.compute_size(root, &children);
+            }
+        }
+        let mut next_pos = 0;
+        for root in 0..elements {
+            if index.parent[root].is_none() {
+                index.decompose(root, root, 0, &mut children, &mut next_pos);
+            }
+        }
+
+        index
+    }
+
+    fn compute_size(&mut self, node: usize, children: &[Vec<usize>]) {
+        let mut size = 1;
+        for &child in &children[node] {
+            self.compute_size(child, children);
+            size += self.size[child];
+        }
+        self.size[node] = size;
+    }
+
+    fn decompose(
+        &mut self,
+        node: usize,
+        head: usize,
+        depth: usize,
+        children: &mut [Vec<usize>],
+        next_pos: &mut usize,
+    ) {
+        self.head[node] = head;
+        self.depth[node] = depth;
+        self.pos[node] = *next_pos;
+        *next_pos += 1;
+
+        let heaviest = children[node]
+            .iter()
+            .copied()
+            .max_by_key(|&child| self.size[child]);
+
+        if let Some(heavy) = heaviest {
+            // Extend the current chain through the heavy child...
+            self.decompose(heavy, head, depth + 1, children, next_pos);
+            // ...then start a fresh chain for each light child.
+            let light: Vec<usize> = std::mem::take(&mut children[node]);
+            for child in light {
+                if child != heavy {
+                    self.decompose(child, child, depth + 1, children, next_pos);
+                }
+            }
+        }
+    }
+
+    /// The linearized position of `id`.
+    #[inline]
+    pub fn position(&self, id: impl ValidId<Entity = E>) -> usize {
+        self.pos[id.id().index()]
+    }
+
+    /// Depth of `id` below its root (the root itself is at depth `0`).
+    #[inline]
+    pub fn depth(&self, id: impl ValidId<Entity = E>) -> usize {
+        self.depth[id.id().index()]
+    }
+
+    /// Number of nodes in the subtree rooted at `id`, including `id`.
+    #[inline]
+    pub fn subtree_size(&self, id: impl ValidId<Entity = E>) -> usize {
+        self.size[id.id().index()]
+    }
+
+    /// The contiguous position range `[pos, pos + size)` covering the subtree
+    /// rooted at `id`.
+    #[inline]
+    pub fn subtree_range(&self, id: impl ValidId<Entity = E>) -> Range<usize> {
+        let row = id.id().index();
+        self.pos[row]..self.pos[row] + self.size[row]
+    }
+
+    /// The chain segments (inclusive `[start, end]` position ranges) covering
+    /// the path between `u` and `v`, for aggregating over that path. Returns an
+    /// empty vec when the two nodes lie in different trees.
+    pub fn path_segments<V0: ValidId<Entity = E>, V1: ValidId<Entity = E>>(
+        &self,
+        u: V0,
+        v: V1,
+    ) -> Vec<(usize, usize)> {
+        let mut u = u.id().index();
+        let mut v = v.id().index();
+        let mut segments = Vec::new();
+
+        while self.head[u] != self.head[v] {
+            // Lift the endpoint whose chain head is deeper.
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            let head = self.head[u];
+            segments.push((self.pos[head], self.pos[u]));
+            match self.parent[head] {
+                Some(p) => u = p,
+                None => return Vec::new(),
+            }
+        }
+
+        let (lo, hi) = (self.pos[u].min(self.pos[v]), self.pos[u].max(self.pos[v]));
+        segments.push((lo, hi));
+        segments
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gen_id::{Entity, Fixed, RangeAllocator};
+
+    #[derive(Debug)]
+    struct Arena;
+
+    impl Entity for Arena {
+        type IdType = Fixed;
+    }
+
+    fn covered(segments: &[(usize, usize)]) -> Vec<usize> {
+        let mut positions: Vec<usize> = segments
+            .iter()
+            .flat_map(|&(lo, hi)| lo..=hi)
+            .collect();
+        positions.sort_unstable();
+        positions
+    }
+
+    #[test]
+    fn subtree_range_covers_the_whole_tree() {
+        let mut graph = RangeRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+        let b = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+        graph.insert_child(b, root);
+
+        let hld = HldIndex::new(&graph);
+        assert_eq!(hld.subtree_range(root), 0..3);
+        assert_eq!(hld.subtree_size(root), 3);
+    }
+
+    #[test]
+    fn path_segments_cover_both_endpoints_and_their_ancestor() {
+        let mut graph = RangeRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+        let b = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+        graph.insert_child(b, root);
+
+        let hld = HldIndex::new(&graph);
+        let segments = hld.path_segments(a, b);
+
+        let mut expected = vec![hld.position(a), hld.position(root), hld.position(b)];
+        expected.sort_unstable();
+        assert_eq!(covered(&segments), expected);
+    }
+}
@@ -3,8 +3,12 @@ use gen_id::{component::RawComponent, Id, IdRange, ValidId};
 use iter_context::ContextualIterator;
 use std::ops::Index;
 
+mod hld;
 mod range;
+mod reachability;
 mod vec;
 
+pub use hld::*;
 pub use range::*;
+pub use reachability::*;
 pub use vec::*;
@@ -1,5 +1,5 @@
 use super::*;
-use gen_id::{Entity, Fixed};
+use gen_id::Entity;
 
 #[derive(Debug, ForceCopy, ForceClone, ForceEq, ForcePartialEq)]
 pub enum RangeRelation<E: Entity> {
@@ -45,8 +45,20 @@ pub struct RangeRelations<E: Entity> {
     values: RawComponent<E, RangeRelation<E>>,
 }
 
-/// Requires fixed because unlinking is not implemented
-impl<E: Entity<IdType = Fixed>> RangeRelations<E> {
+/// Returned when a child cannot be unlinked without breaking the contiguous
+/// id range that `RangeRelation::ParentOf` relies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonContiguous;
+
+impl std::fmt::Display for NonContiguous {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("removing child would leave a non-contiguous parent range")
+    }
+}
+
+impl std::error::Error for NonContiguous {}
+
+impl<E: Entity> RangeRelations<E> {
     #[inline]
     #[track_caller]
     fn insert_if_empty(&mut self, id: impl ValidId<Entity = E>, relation: RangeRelation<E>) {
@@ -81,6 +93,178 @@ impl<E: Entity<IdType = Fixed>> RangeRelations<E> {
         self.insert_if_empty(id, relation);
     }
 
+    /// Detaches `child` from its parent, removing its id from the parent's
+    /// `ParentOf` range and turning `child` into a parentless root.
+    ///
+    /// Because `ParentOf` stores a contiguous [`IdRange`], a child can only be
+    /// removed from either end of the range. Removing one from the middle would
+    /// split the range in two, so [`NonContiguous`] is returned and the graph is
+    /// left unchanged.
+    #[inline]
+    #[track_caller]
+    pub fn remove_child(
+        &mut self,
+        child: impl ValidId<Entity = E>,
+    ) -> Result<(), NonContiguous> {
+        let child = child.id();
+        let parent = match self.values[child] {
+            RangeRelation::ChildOf(parent) => parent,
+            RangeRelation::ParentOf(_) => panic!("id is not a child"),
+        };
+
+        let range = match self.values[parent] {
+            RangeRelation::ParentOf(range) => range,
+            RangeRelation::ChildOf(_) => panic!("parent id is not a parent"),
+        };
+
+        let shrunk = Self::remove_from_range(range, child)?;
+        self.values[parent] = RangeRelation::ParentOf(shrunk);
+        self.values[child] = RangeRelation::parent();
+        Ok(())
+    }
+
+    /// Rebuilds `range` without `child`, succeeding only when the removal leaves
+    /// the remaining ids contiguous (i.e. `child` sat at one end of the range).
+    #[inline]
+    fn remove_from_range(range: IdRange<E>, child: Id<E>) -> Result<IdRange<E>, NonContiguous> {
+        let len = range.len();
+        let position = range
+            .into_iter()
+            .position(|id| id == child)
+            .expect("child id not found in parent range");
+
+        // Only an endpoint can be dropped while keeping the remainder contiguous.
+        if len > 1 && position != 0 && position != len - 1 {
+            return Err(NonContiguous);
+        }
+
+        let mut shrunk = IdRange::default();
+        for id in range.into_iter().filter(|id| *id != child) {
+            shrunk.append(id);
+        }
+        Ok(shrunk)
+    }
+
+    /// Walks `ChildOf` links upward, yielding each parent of `id` in turn up to
+    /// the root. The starting id is not included.
+    #[inline]
+    pub fn ancestors(&self, id: impl ValidId<Entity = E>) -> RangeAncestors<'_, E> {
+        RangeAncestors {
+            relations: self,
+            current: id.id(),
+        }
+    }
+
+    /// Walks `ParentOf` children depth-first, yielding every descendant of `id`.
+    /// The starting id is not included.
+    #[inline]
+    pub fn descendants(&self, id: impl ValidId<Entity = E>) -> RangeDescendants<'_, E> {
+        let mut stack = Vec::new();
+        if let Some(children) = self[id.id()].parent_of() {
+            stack.extend(children);
+        }
+        RangeDescendants {
+            relations: self,
+            stack,
+        }
+    }
+
+    /// Returns the lowest common ancestor of `a` and `b`, or `None` when they do
+    /// not share one (i.e. they sit in different trees of the forest). A node is
+    /// considered its own ancestor, so the result is `a` when `a` is an ancestor
+    /// of `b` (and vice versa).
+    #[inline]
+    pub fn lowest_common_ancestor<V0: ValidId<Entity = E>, V1: ValidId<Entity = E>>(
+        &self,
+        a: V0,
+        b: V1,
+    ) -> Option<Id<E>> {
+        let a = a.id();
+        let chain: Vec<Id<E>> = std::iter::once(a).chain(self.ancestors(a)).collect();
+        std::iter::once(b.id())
+            .chain(self.ancestors(b))
+            .find(|id| chain.contains(id))
+    }
+
+    /// Computes the full transitive ancestor/descendant closure of the forest
+    /// as a packed [`Reachability`] bit matrix.
+    ///
+    /// Because the relations form a forest, a single post-order sweep — unioning
+    /// each child's reachable set into its parent's — produces the closure
+    /// without a fixpoint loop. See [`reachability_fixpoint`] for the
+    /// iterate-until-stable variant that also handles general DAG-like usage.
+    ///
+    /// [`reachability_fixpoint`]: RangeRelations::reachability_fixpoint
+    #[inline]
+    pub fn reachability(&self) -> Reachability<E> {
+        let mut reach = Reachability::with_elements(self.into_iter().count());
+        for (row, relation) in self.into_iter().enumerate() {
+            // `ParentOf` nodes are never children, so each is a distinct root.
+            if let RangeRelation::ParentOf(children) = relation {
+                sweep(self, &mut reach, row, *children);
+            }
+        }
+        reach
+    }
+
+    /// Computes the transitive closure by seeding direct `ParentOf` edges and
+    /// repeatedly unioning each edge's target row into its source row until no
+    /// bit changes. Slower than [`reachability`](RangeRelations::reachability)
+    /// on forests, but correct for general DAG-like edge sets.
+    #[inline]
+    pub fn reachability_fixpoint(&self) -> Reachability<E> {
+        let mut reach = Reachability::with_elements(self.into_iter().count());
+        let mut edges = Vec::new();
+        for (row, relation) in self.into_iter().enumerate() {
+            if let RangeRelation::ParentOf(children) = relation {
+                for child in *children {
+                    let col = child.index();
+                    reach.set(row, col);
+                    edges.push((row, col));
+                }
+            }
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &(row, target) in &edges {
+                changed |= reach.union_row(row, target);
+            }
+        }
+        reach
+    }
+
+    /// Folds each parent's value into its children top-down, replacing every
+    /// child's value with `combine(parent_value, child_local_value)` — the
+    /// building block for accumulated attributes such as world transforms
+    /// derived from local ones. Parents are always visited before their
+    /// children, so a node sees its parent's already-combined value.
+    pub fn propagate<C, F>(
+        &self,
+        roots: impl IntoIterator<Item = Id<E>>,
+        component: &mut RawComponent<E, C>,
+        combine: F,
+    ) where
+        F: Fn(&C, &C) -> C,
+    {
+        for root in roots {
+            self.propagate_from(root, component, &combine);
+        }
+    }
+
+    fn propagate_from<C, F>(&self, node: Id<E>, component: &mut RawComponent<E, C>, combine: &F)
+    where
+        F: Fn(&C, &C) -> C,
+    {
+        if let Some(children) = self[node].parent_of() {
+            for child in children {
+                component[child] = combine(&component[node], &component[child]);
+                self.propagate_from(child, component, combine);
+            }
+        }
+    }
+
     #[inline]
     pub fn parents<'a, I: IntoIterator<Item = Id<E>> + 'a>(
         &'a self,
@@ -89,6 +273,147 @@ impl<E: Entity<IdType = Fixed>> RangeRelations<E> {
         iter.into_iter()
             .filter(move |id| matches!(self[id], RangeRelation::ParentOf(_)))
     }
+
+    #[inline]
+    pub fn children<'a, I: IntoIterator<Item = Id<E>> + 'a>(
+        &'a self,
+        iter: I,
+    ) -> impl Iterator<Item = Id<E>> + 'a {
+        iter.into_iter()
+            .filter(move |id| matches!(self[id], RangeRelation::ChildOf(_)))
+    }
+}
+
+/// Merges two id streams, both assumed sorted by the dense `RawComponent`
+/// order, into their union in a single linear pass without allocating.
+#[inline]
+pub fn union<E: Entity>(
+    a: impl Iterator<Item = Id<E>>,
+    b: impl Iterator<Item = Id<E>>,
+) -> impl Iterator<Item = Id<E>> {
+    let mut a = a.peekable();
+    let mut b = b.peekable();
+    std::iter::from_fn(move || match (a.peek(), b.peek()) {
+        (Some(x), Some(y)) => match x.index().cmp(&y.index()) {
+            std::cmp::Ordering::Less => a.next(),
+            std::cmp::Ordering::Greater => b.next(),
+            std::cmp::Ordering::Equal => {
+                b.next();
+                a.next()
+            }
+        },
+        (Some(_), None) => a.next(),
+        (None, _) => b.next(),
+    })
+}
+
+/// Merges two id streams, both assumed sorted by the dense `RawComponent`
+/// order, into their intersection in a single linear pass without allocating.
+#[inline]
+pub fn intersection<E: Entity>(
+    a: impl Iterator<Item = Id<E>>,
+    b: impl Iterator<Item = Id<E>>,
+) -> impl Iterator<Item = Id<E>> {
+    let mut a = a.peekable();
+    let mut b = b.peekable();
+    std::iter::from_fn(move || loop {
+        let (x, y) = (a.peek()?, b.peek()?);
+        match x.index().cmp(&y.index()) {
+            std::cmp::Ordering::Less => {
+                a.next();
+            }
+            std::cmp::Ordering::Greater => {
+                b.next();
+            }
+            std::cmp::Ordering::Equal => {
+                b.next();
+                return a.next();
+            }
+        }
+    })
+}
+
+/// Yields the ids in `a` that are absent from `b`, both assumed sorted by the
+/// dense `RawComponent` order, in a single linear pass without allocating.
+#[inline]
+pub fn difference<E: Entity>(
+    a: impl Iterator<Item = Id<E>>,
+    b: impl Iterator<Item = Id<E>>,
+) -> impl Iterator<Item = Id<E>> {
+    let mut a = a.peekable();
+    let mut b = b.peekable();
+    std::iter::from_fn(move || loop {
+        match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) => match x.index().cmp(&y.index()) {
+                std::cmp::Ordering::Less => return a.next(),
+                std::cmp::Ordering::Greater => {
+                    b.next();
+                }
+                std::cmp::Ordering::Equal => {
+                    a.next();
+                    b.next();
+                }
+            },
+            (Some(_), None) => return a.next(),
+            (None, _) => return None,
+        }
+    })
+}
+
+/// Post-order sweep: recurse into each child's subtree, then record the edge
+/// and fold the child's reachable set into `row`.
+fn sweep<E: Entity>(
+    relations: &RangeRelations<E>,
+    reach: &mut Reachability<E>,
+    row: usize,
+    children: IdRange<E>,
+) {
+    for child in children {
+        let col = child.index();
+        if let RangeRelation::ParentOf(grandchildren) = relations[child] {
+            sweep(relations, reach, col, grandchildren);
+        }
+        reach.set(row, col);
+        reach.union_row(row, col);
+    }
+}
+
+/// Iterator over the `ChildOf` chain above a node, produced by
+/// [`RangeRelations::ancestors`].
+pub struct RangeAncestors<'a, E: Entity> {
+    relations: &'a RangeRelations<E>,
+    current: Id<E>,
+}
+
+impl<E: Entity> Iterator for RangeAncestors<'_, E> {
+    type Item = Id<E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let parent = self.relations[self.current].child_of()?;
+        self.current = parent;
+        Some(parent)
+    }
+}
+
+/// Depth-first iterator over the `ParentOf` subtree below a node, produced by
+/// [`RangeRelations::descendants`].
+pub struct RangeDescendants<'a, E: Entity> {
+    relations: &'a RangeRelations<E>,
+    stack: Vec<Id<E>>,
+}
+
+impl<E: Entity> Iterator for RangeDescendants<'_, E> {
+    type Item = Id<E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.stack.pop()?;
+        if let Some(children) = self.relations[id].parent_of() {
+            self.stack.extend(children);
+        }
+        Some(id)
+    }
 }
 
 impl<E: Entity, V: ValidId<Entity = E>> Index<V> for RangeRelations<E> {
@@ -192,4 +517,173 @@ mod test {
         graph.insert_parent(id1);
         graph.insert_child(id1, id0);
     }
+
+    #[test]
+    fn remove_last_child_shrinks_range() {
+        let mut graph = RangeRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let id0 = alloc.create();
+        let id1 = alloc.create();
+        let id2 = alloc.create();
+
+        graph.insert_parent(id0);
+        graph.insert_child(id1, id0);
+        graph.insert_child(id2, id0);
+
+        graph.remove_child(id2).unwrap();
+
+        assert_eq!(graph[id0], RangeRelation::ParentOf(IdRange::from(id1.id())));
+        assert_eq!(graph[id2], RangeRelation::parent());
+    }
+
+    #[test]
+    fn remove_interior_child_is_non_contiguous() {
+        let mut graph = RangeRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let id0 = alloc.create();
+        let id1 = alloc.create();
+        let id2 = alloc.create();
+        let id3 = alloc.create();
+
+        graph.insert_parent(id0);
+        graph.insert_child(id1, id0);
+        graph.insert_child(id2, id0);
+        graph.insert_child(id3, id0);
+
+        assert_eq!(graph.remove_child(id2), Err(NonContiguous));
+    }
+
+    #[test]
+    fn children_filters_child_ids() {
+        let mut graph = RangeRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+
+        let children: Vec<_> = graph.children([root.id(), a.id()]).collect();
+        assert_eq!(children, vec![a.id()]);
+    }
+
+    #[test]
+    fn set_combinators_merge_sorted_streams() {
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let id0 = alloc.create().id();
+        let id1 = alloc.create().id();
+        let id2 = alloc.create().id();
+
+        let lhs = || [id0, id2].into_iter();
+        let rhs = || [id1, id2].into_iter();
+
+        assert_eq!(
+            union(lhs(), rhs()).collect::<Vec<_>>(),
+            vec![id0, id1, id2]
+        );
+        assert_eq!(intersection(lhs(), rhs()).collect::<Vec<_>>(), vec![id2]);
+        assert_eq!(difference(lhs(), rhs()).collect::<Vec<_>>(), vec![id0]);
+    }
+
+    #[test]
+    fn propagate_folds_parent_values_into_children() {
+        let mut graph = RangeRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+        let b = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+        graph.insert_child(b, root);
+
+        let mut values = RawComponent::<Arena, i32>::default();
+        values.insert(root.id(), 1);
+        values.insert(a.id(), 10);
+        values.insert(b.id(), 20);
+
+        graph.propagate([root.id()], &mut values, |parent, local| parent + local);
+
+        assert_eq!(values[root.id()], 1);
+        assert_eq!(values[a.id()], 11);
+        assert_eq!(values[b.id()], 21);
+    }
+
+    #[test]
+    fn reachability_marks_ancestors() {
+        let mut graph = RangeRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+        let b = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+        graph.insert_child(b, root);
+
+        for reach in [graph.reachability(), graph.reachability_fixpoint()] {
+            assert!(reach.is_ancestor_of(root, a));
+            assert!(reach.is_ancestor_of(root, b));
+            assert!(!reach.is_ancestor_of(a, root));
+            assert!(!reach.is_ancestor_of(a, b));
+        }
+    }
+
+    #[test]
+    fn ancestors_walk_up_to_root() {
+        let mut graph = RangeRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let child = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(child, root);
+
+        let ancestors: Vec<_> = graph.ancestors(child).collect();
+        assert_eq!(ancestors, vec![root.id()]);
+        assert_eq!(graph.ancestors(root).count(), 0);
+    }
+
+    #[test]
+    fn descendants_walk_the_subtree() {
+        let mut graph = RangeRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+        let b = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+        graph.insert_child(b, root);
+
+        let descendants: Vec<_> = graph.descendants(root).collect();
+        assert_eq!(descendants.len(), 2);
+        assert!(descendants.contains(&a.id()));
+        assert!(descendants.contains(&b.id()));
+    }
+
+    #[test]
+    fn lowest_common_ancestor_of_siblings_is_the_parent() {
+        let mut graph = RangeRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+        let b = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+        graph.insert_child(b, root);
+
+        assert_eq!(graph.lowest_common_ancestor(a, b), Some(root.id()));
+        assert_eq!(graph.lowest_common_ancestor(a, root), Some(root.id()));
+    }
 }
@@ -0,0 +1,68 @@
+use super::*;
+use gen_id::Entity;
+use std::marker::PhantomData;
+
+/// Transitive ancestor/descendant closure of a relation forest, packed into a
+/// bit matrix so that [`is_ancestor_of`](Reachability::is_ancestor_of) answers
+/// in `O(1)`.
+///
+/// Bit `(row, col)` is set when the node at dense row index `row` reaches the
+/// node at `col` by following `ParentOf` links downward — i.e. `row` is an
+/// ancestor of `col`. Rows are packed into `ceil(elements / 64)` `u64` words.
+#[derive(Debug, Clone)]
+pub struct Reachability<E: Entity> {
+    elements: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+    marker: PhantomData<E>,
+}
+
+impl<E: Entity> Reachability<E> {
+    #[inline]
+    pub(crate) fn with_elements(elements: usize) -> Self {
+        let words_per_row = elements.div_ceil(64);
+        Self {
+            elements,
+            words_per_row,
+            words: vec![0; elements * words_per_row],
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set(&mut self, row: usize, col: usize) {
+        self.words[row * self.words_per_row + col / 64] |= 1 << (col % 64);
+    }
+
+    /// ORs `src`'s row words into `dst`'s, returning whether any bit was added.
+    #[inline]
+    pub(crate) fn union_row(&mut self, dst: usize, src: usize) -> bool {
+        let mut changed = false;
+        for word in 0..self.words_per_row {
+            let src_word = self.words[src * self.words_per_row + word];
+            let dst_index = dst * self.words_per_row + word;
+            let merged = self.words[dst_index] | src_word;
+            changed |= merged != self.words[dst_index];
+            self.words[dst_index] = merged;
+        }
+        changed
+    }
+
+    #[inline]
+    fn get(&self, row: usize, col: usize) -> bool {
+        if row >= self.elements || col >= self.elements {
+            return false;
+        }
+        self.words[row * self.words_per_row + col / 64] & (1 << (col % 64)) != 0
+    }
+
+    /// Returns `true` when `a` is a (transitive) ancestor of `b`.
+    #[inline]
+    pub fn is_ancestor_of<A: ValidId<Entity = E>, B: ValidId<Entity = E>>(
+        &self,
+        a: A,
+        b: B,
+    ) -> bool {
+        self.get(a.id().index(), b.id().index())
+    }
+}
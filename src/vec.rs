@@ -58,7 +58,6 @@ pub struct VecRelations<E: Entity> {
     values: RawComponent<E, VecRelation<E>>,
 }
 
-/// Requires fixed because unlinking is not implemented
 impl<E: Entity> VecRelations<E> {
     #[inline]
     #[track_caller]
@@ -93,6 +92,237 @@ impl<E: Entity> VecRelations<E> {
         let relation = VecRelation::ChildOf(parent.id());
         self.insert_if_empty(id, relation);
     }
+
+    /// Detaches `child` from its parent, removing its id from the parent's
+    /// `ParentOf` list and turning `child` into a parentless root.
+    #[inline]
+    #[track_caller]
+    pub fn remove_child(&mut self, child: impl ValidId<Entity = E>) {
+        let child = child.id();
+        let parent = match &self.values[child] {
+            VecRelation::ChildOf(parent) => *parent,
+            VecRelation::ParentOf(_) => panic!("id is not a child"),
+        };
+
+        Self::unlink_from_parent(&mut self.values[parent], child);
+        self.values[child] = VecRelation::parent();
+    }
+
+    /// Moves `child` from its current parent to `new_parent`, maintaining both
+    /// sides of the link.
+    #[inline]
+    #[track_caller]
+    pub fn reparent<V0: ValidId<Entity = E>, V1: ValidId<Entity = E>>(
+        &mut self,
+        child: V0,
+        new_parent: V1,
+    ) {
+        let child = child.id();
+        let new_parent = new_parent.id();
+
+        let old_parent = match &self.values[child] {
+            VecRelation::ChildOf(parent) => *parent,
+            VecRelation::ParentOf(_) => panic!("id is not a child"),
+        };
+
+        Self::unlink_from_parent(&mut self.values[old_parent], child);
+        match &mut self.values[new_parent] {
+            VecRelation::ParentOf(children) => children.push(child),
+            VecRelation::ChildOf(_) => panic!("new parent id is not a parent"),
+        }
+        self.values[child] = VecRelation::ChildOf(new_parent);
+    }
+
+    /// Walks `ChildOf` links upward, yielding each parent of `id` in turn up to
+    /// the root. The starting id is not included.
+    #[inline]
+    pub fn ancestors(&self, id: impl ValidId<Entity = E>) -> VecAncestors<'_, E> {
+        VecAncestors {
+            relations: self,
+            current: id.id(),
+        }
+    }
+
+    /// Walks `ParentOf` children depth-first, yielding every descendant of `id`.
+    /// The starting id is not included.
+    #[inline]
+    pub fn descendants(&self, id: impl ValidId<Entity = E>) -> VecDescendants<'_, E> {
+        let mut stack = Vec::new();
+        if let Some(children) = self[id.id()].parent_of() {
+            stack.extend(children.iter().copied());
+        }
+        VecDescendants {
+            relations: self,
+            stack,
+        }
+    }
+
+    /// Returns the lowest common ancestor of `a` and `b`, or `None` when they do
+    /// not share one (i.e. they sit in different trees of the forest). A node is
+    /// considered its own ancestor, so the result is `a` when `a` is an ancestor
+    /// of `b` (and vice versa).
+    #[inline]
+    pub fn lowest_common_ancestor<V0: ValidId<Entity = E>, V1: ValidId<Entity = E>>(
+        &self,
+        a: V0,
+        b: V1,
+    ) -> Option<Id<E>> {
+        let a = a.id();
+        let chain: Vec<Id<E>> = std::iter::once(a).chain(self.ancestors(a)).collect();
+        std::iter::once(b.id())
+            .chain(self.ancestors(b))
+            .find(|id| chain.contains(id))
+    }
+
+    /// Computes the full transitive ancestor/descendant closure of the forest
+    /// as a packed [`Reachability`] bit matrix.
+    ///
+    /// Because the relations form a forest, a single post-order sweep — unioning
+    /// each child's reachable set into its parent's — produces the closure
+    /// without a fixpoint loop. See [`reachability_fixpoint`] for the
+    /// iterate-until-stable variant that also handles general DAG-like usage.
+    ///
+    /// [`reachability_fixpoint`]: VecRelations::reachability_fixpoint
+    #[inline]
+    pub fn reachability(&self) -> Reachability<E> {
+        let mut reach = Reachability::with_elements(self.into_iter().count());
+        for (row, relation) in self.into_iter().enumerate() {
+            // `ParentOf` nodes are never children, so each is a distinct root.
+            if let VecRelation::ParentOf(children) = relation {
+                sweep(self, &mut reach, row, children);
+            }
+        }
+        reach
+    }
+
+    /// Computes the transitive closure by seeding direct `ParentOf` edges and
+    /// repeatedly unioning each edge's target row into its source row until no
+    /// bit changes. Slower than [`reachability`](VecRelations::reachability) on
+    /// forests, but correct for general DAG-like edge sets.
+    #[inline]
+    pub fn reachability_fixpoint(&self) -> Reachability<E> {
+        let mut reach = Reachability::with_elements(self.into_iter().count());
+        let mut edges = Vec::new();
+        for (row, relation) in self.into_iter().enumerate() {
+            if let VecRelation::ParentOf(children) = relation {
+                for child in children {
+                    let col = child.index();
+                    reach.set(row, col);
+                    edges.push((row, col));
+                }
+            }
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &(row, target) in &edges {
+                changed |= reach.union_row(row, target);
+            }
+        }
+        reach
+    }
+
+    /// Folds each parent's value into its children top-down, replacing every
+    /// child's value with `combine(parent_value, child_local_value)` — the
+    /// building block for accumulated attributes such as world transforms
+    /// derived from local ones. Parents are always visited before their
+    /// children, so a node sees its parent's already-combined value.
+    pub fn propagate<C, F>(
+        &self,
+        roots: impl IntoIterator<Item = Id<E>>,
+        component: &mut RawComponent<E, C>,
+        combine: F,
+    ) where
+        F: Fn(&C, &C) -> C,
+    {
+        for root in roots {
+            self.propagate_from(root, component, &combine);
+        }
+    }
+
+    fn propagate_from<C, F>(&self, node: Id<E>, component: &mut RawComponent<E, C>, combine: &F)
+    where
+        F: Fn(&C, &C) -> C,
+    {
+        if let Some(children) = self[node].parent_of() {
+            for &child in children {
+                component[child] = combine(&component[node], &component[child]);
+                self.propagate_from(child, component, combine);
+            }
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn unlink_from_parent(parent: &mut VecRelation<E>, child: Id<E>) {
+        match parent {
+            VecRelation::ParentOf(children) => {
+                let index = children
+                    .iter()
+                    .position(|c| *c == child)
+                    .expect("child id not found in parent");
+                children.swap_remove(index);
+            }
+            VecRelation::ChildOf(_) => panic!("parent id is not a parent"),
+        }
+    }
+}
+
+/// Post-order sweep: recurse into each child's subtree, then record the edge
+/// and fold the child's reachable set into `row`.
+fn sweep<E: Entity>(
+    relations: &VecRelations<E>,
+    reach: &mut Reachability<E>,
+    row: usize,
+    children: &[Id<E>],
+) {
+    for &child in children {
+        let col = child.index();
+        if let VecRelation::ParentOf(grandchildren) = &relations[child] {
+            sweep(relations, reach, col, grandchildren);
+        }
+        reach.set(row, col);
+        reach.union_row(row, col);
+    }
+}
+
+/// Iterator over the `ChildOf` chain above a node, produced by
+/// [`VecRelations::ancestors`].
+pub struct VecAncestors<'a, E: Entity> {
+    relations: &'a VecRelations<E>,
+    current: Id<E>,
+}
+
+impl<E: Entity> Iterator for VecAncestors<'_, E> {
+    type Item = Id<E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let parent = self.relations[self.current].child_of()?;
+        self.current = parent;
+        Some(parent)
+    }
+}
+
+/// Depth-first iterator over the `ParentOf` subtree below a node, produced by
+/// [`VecRelations::descendants`].
+pub struct VecDescendants<'a, E: Entity> {
+    relations: &'a VecRelations<E>,
+    stack: Vec<Id<E>>,
+}
+
+impl<E: Entity> Iterator for VecDescendants<'_, E> {
+    type Item = Id<E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.stack.pop()?;
+        if let Some(children) = self.relations[id].parent_of() {
+            self.stack.extend(children.iter().copied());
+        }
+        Some(id)
+    }
 }
 
 impl<E: Entity, V: ValidId<Entity = E>> Index<V> for VecRelations<E> {
@@ -196,4 +426,149 @@ mod test {
         graph.insert_parent(id1);
         graph.insert_child(id1, id0);
     }
+
+    #[test]
+    fn remove_child_clears_both_sides() {
+        let mut graph = VecRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let id0 = alloc.create();
+        let id1 = alloc.create();
+
+        graph.insert_parent(id0);
+        graph.insert_child(id1, id0);
+        graph.remove_child(id1);
+
+        assert_eq!(graph[id0], VecRelation::ParentOf(vec![]));
+        assert_eq!(graph[id1], VecRelation::parent());
+    }
+
+    #[test]
+    fn reparent_moves_child_between_parents() {
+        let mut graph = VecRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let id0 = alloc.create();
+        let id1 = alloc.create();
+        let id2 = alloc.create();
+
+        graph.insert_parent(id0);
+        graph.insert_parent(id1);
+        graph.insert_child(id2, id0);
+        graph.reparent(id2, id1);
+
+        assert_eq!(graph[id0], VecRelation::ParentOf(vec![]));
+        assert_eq!(graph[id1], VecRelation::ParentOf(vec![id2.id()]));
+        assert_eq!(graph[id2], VecRelation::ChildOf(id1.id()));
+    }
+
+    #[test]
+    fn ancestors_walk_up_to_root() {
+        let mut graph = VecRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let child = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(child, root);
+
+        let ancestors: Vec<_> = graph.ancestors(child).collect();
+        assert_eq!(ancestors, vec![root.id()]);
+        assert_eq!(graph.ancestors(root).count(), 0);
+    }
+
+    #[test]
+    fn descendants_walk_the_subtree() {
+        let mut graph = VecRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+        let b = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+        graph.insert_child(b, root);
+
+        let descendants: Vec<_> = graph.descendants(root).collect();
+        assert_eq!(descendants.len(), 2);
+        assert!(descendants.contains(&a.id()));
+        assert!(descendants.contains(&b.id()));
+    }
+
+    #[test]
+    fn lowest_common_ancestor_of_siblings_is_the_parent() {
+        let mut graph = VecRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+        let b = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+        graph.insert_child(b, root);
+
+        assert_eq!(graph.lowest_common_ancestor(a, b), Some(root.id()));
+        assert_eq!(graph.lowest_common_ancestor(a, root), Some(root.id()));
+    }
+
+    #[test]
+    fn reachability_marks_ancestors() {
+        let mut graph = VecRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+        let b = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+        graph.insert_child(b, root);
+
+        for reach in [graph.reachability(), graph.reachability_fixpoint()] {
+            assert!(reach.is_ancestor_of(root, a));
+            assert!(reach.is_ancestor_of(root, b));
+            assert!(!reach.is_ancestor_of(a, root));
+            assert!(!reach.is_ancestor_of(a, b));
+        }
+    }
+
+    #[test]
+    fn propagate_folds_parent_values_into_children() {
+        let mut graph = VecRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let root = alloc.create();
+        let a = alloc.create();
+        let b = alloc.create();
+
+        graph.insert_parent(root);
+        graph.insert_child(a, root);
+        graph.insert_child(b, root);
+
+        let mut values = RawComponent::<Arena, i32>::default();
+        values.insert(root.id(), 1);
+        values.insert(a.id(), 10);
+        values.insert(b.id(), 20);
+
+        graph.propagate([root.id()], &mut values, |parent, local| parent + local);
+
+        assert_eq!(values[root.id()], 1);
+        assert_eq!(values[a.id()], 11);
+        assert_eq!(values[b.id()], 21);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_child_on_parent_panics() {
+        let mut graph = VecRelations::<Arena>::default();
+        let mut alloc = RangeAllocator::<Arena>::default();
+
+        let id0 = alloc.create();
+
+        graph.insert_parent(id0);
+        graph.remove_child(id0);
+    }
 }